@@ -1,16 +1,96 @@
 use std::path::{Path, PathBuf};
 use std::process::{Command, Stdio};
-use std::time::Duration;
+use std::sync::mpsc::RecvTimeoutError;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 #[cfg(windows)]
 use std::os::windows::process::CommandExt;
 use std::thread;
 use std::io::{BufRead, BufReader};
 
-use tauri::Manager;
+use serde::Serialize;
+use shared_child::SharedChild;
+use tauri::{Emitter, Manager};
+
+use crate::error::BackendError;
+
+/// How long `stop`/`Drop` wait for the backend to exit cleanly after asking
+/// it to shut down before falling back to a hard kill.
+const GRACEFUL_SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// How many candidate ports `spawn_server` will try before giving up.
+const MAX_PORT_ATTEMPTS: u32 = 5;
+/// How long to wait for the backend to report its port before assuming it's
+/// wedged (first-run may be slow to import, so this is generous).
+const PORT_WAIT_TIMEOUT: Duration = Duration::from_secs(60);
+const HEALTH_CHECK_ATTEMPTS: u32 = 30;
+const HEALTH_CHECK_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Outcome of one `launch_on_port` attempt: a bind conflict is retried with a
+/// fresh port by `spawn_server`; anything else is a real failure.
+enum PortAttemptError {
+    PortConflict,
+    Other(BackendError),
+}
+
+impl From<BackendError> for PortAttemptError {
+    fn from(e: BackendError) -> Self {
+        PortAttemptError::Other(e)
+    }
+}
+
+/// Find a free TCP port on 127.0.0.1 by binding to an ephemeral port and
+/// immediately releasing it. Racy in general — another process can grab it
+/// before the backend gets to `listen()` — which is exactly what
+/// `launch_on_port`'s early-exit check is there to catch.
+fn find_free_port() -> Result<u16, BackendError> {
+    std::net::TcpListener::bind("127.0.0.1:0")
+        .and_then(|l| l.local_addr())
+        .map(|addr| addr.port())
+        .map_err(|e| BackendError::io("Failed to find a free port", e))
+}
 
 pub struct BackendProcess {
-    child: Option<std::process::Child>,
+    // Shared so the stdout/stderr reader threads and the stop path can all
+    // observe and signal the same child without taking ownership of it.
+    child: Option<Arc<SharedChild>>,
     pub port: u16,
+    // Which interpreter version this instance was set up against, if the
+    // user pinned one via `SUZENT_PYTHON_VERSION`. Kept so `restart` resolves
+    // the same venv that `start` set up.
+    python_version: Option<String>,
+}
+
+/// A single step in the first-run setup pipeline, emitted to the frontend as
+/// the `backend-setup-progress` event so the UI can render a progress bar
+/// and a live log instead of staring at a silent window.
+#[derive(Clone, Serialize)]
+pub struct SetupStage {
+    pub stage: String,
+    pub detail: String,
+    pub pct: u8,
+}
+
+/// Emit a `backend-setup-progress` event, if an app handle is available.
+/// Setup can run from the CLI path (no running Tauri app yet), so this is a
+/// no-op rather than an error when `app_handle` is `None`.
+fn emit_progress(app_handle: Option<&tauri::AppHandle>, stage: &str, detail: &str, pct: u8) {
+    if let Some(app_handle) = app_handle {
+        let _ = app_handle.emit("backend-setup-progress", SetupStage {
+            stage: stage.to_string(),
+            detail: detail.to_string(),
+            pct,
+        });
+    }
+}
+
+/// Emit a `backend-setup-warning` event for a non-fatal setup failure (setup
+/// continues either way), so the frontend can still show the user an
+/// actionable, structured error instead of it only going to the console.
+fn emit_setup_warning(app_handle: Option<&tauri::AppHandle>, err: BackendError) {
+    if let Some(app_handle) = app_handle {
+        let _ = app_handle.emit("backend-setup-warning", &err);
+    }
 }
 
 impl BackendProcess {
@@ -18,48 +98,107 @@ impl BackendProcess {
         BackendProcess {
             child: None,
             port: 0,
+            python_version: None,
         }
     }
 
     /// Start the Python backend by launching the bundled Python interpreter.
     /// Only called in release builds - in debug mode the backend runs separately.
+    /// Honors `SUZENT_PYTHON_VERSION` (e.g. "3.11") to pin an interpreter
+    /// other than the single bundled one.
     #[allow(dead_code)]
-    pub fn start(&mut self, app_handle: &tauri::AppHandle) -> Result<u16, String> {
+    pub fn start(&mut self, app_handle: &tauri::AppHandle) -> Result<u16, BackendError> {
         let app_data_dir = app_handle.path()
             .app_data_dir()
-            .map_err(|e| format!("Failed to get app data dir: {}", e))?;
+            .map_err(|e| BackendError::AppDataDir(e.to_string()))?;
 
         std::fs::create_dir_all(&app_data_dir)
-            .map_err(|e| format!("Failed to create app data dir: {}", e))?;
+            .map_err(|e| BackendError::io(format!("Failed to create app data dir {:?}", app_data_dir), e))?;
 
         let resource_dir = app_handle.path()
             .resource_dir()
-            .map_err(|e| format!("Failed to get resource dir: {}", e))?;
+            .map_err(|e| BackendError::ResourceDir(e.to_string()))?;
+
+        self.python_version = std::env::var("SUZENT_PYTHON_VERSION").ok();
 
         // First-run setup: create/update venv from bundled wheel
-        ensure_backend_setup(&resource_dir, &app_data_dir)?;
+        ensure_backend_setup(&resource_dir, &app_data_dir, Some(app_handle), self.python_version.as_deref())?;
 
         // Copy config and skills to app data dir if needed
-        sync_app_data(&resource_dir, &app_data_dir)?;
+        sync_app_data(&resource_dir, &app_data_dir, Some(app_handle))?;
 
+        self.spawn_server(app_handle, &app_data_dir)
+    }
+
+    /// Restart the backend subprocess in place: stop the current child and
+    /// spawn a fresh one against the same app data dir. Used by
+    /// `BackendWatcher` when skill/config files change on disk, so it skips
+    /// the venv/wheel setup that `start` performs.
+    ///
+    /// Deliberately does *not* re-run `sync_app_data`: that copies bundled
+    /// `.example.` files into the very directories the watcher watches, so
+    /// re-syncing here would make every restart write files that trigger
+    /// another debounced change, restarting forever.
+    pub fn restart(
+        &mut self,
+        app_handle: &tauri::AppHandle,
+        app_data_dir: &Path,
+    ) -> Result<u16, BackendError> {
+        self.stop();
+        self.spawn_server(app_handle, app_data_dir)
+    }
+
+    /// Spawn the `suzent.server` subprocess against an already-prepared venv
+    /// and app data dir, probing for a free port (retrying on conflict), then
+    /// wait for it to become healthy. Shared by `start` and `restart`.
+    fn spawn_server(&mut self, app_handle: &tauri::AppHandle, app_data_dir: &Path) -> Result<u16, BackendError> {
         // Resolve python executable inside the venv
-        let venv_dir = app_data_dir.join("backend-venv");
+        let venv_dir = venv_dir_for(app_data_dir, self.python_version.as_deref());
         let python_exe = get_venv_python(&venv_dir);
 
         if !python_exe.exists() {
-            return Err(format!("Python not found at {:?}", python_exe));
+            return Err(BackendError::VenvPythonMissing(python_exe));
         }
 
         // Generate CLI shim
-        ensure_cli_shim(&app_data_dir, &python_exe)?;
+        ensure_cli_shim(&app_data_dir, &python_exe, self.python_version.as_deref())?;
+
+        for attempt in 1..=MAX_PORT_ATTEMPTS {
+            let candidate_port = find_free_port()?;
+            match self.launch_on_port(app_handle, app_data_dir, &venv_dir, &python_exe, candidate_port) {
+                Ok(port) => return Ok(port),
+                Err(PortAttemptError::PortConflict) if attempt < MAX_PORT_ATTEMPTS => {
+                    println!("  Port {} unavailable, retrying with a new port ({}/{})...", candidate_port, attempt, MAX_PORT_ATTEMPTS);
+                    continue;
+                }
+                Err(PortAttemptError::PortConflict) => return Err(BackendError::PortExhausted),
+                Err(PortAttemptError::Other(e)) => return Err(e),
+            }
+        }
+
+        Err(BackendError::PortExhausted)
+    }
+
+    /// Spawn the backend on a specific `port`, wait for it to either report
+    /// readiness or die (distinguishing a bind conflict from other startup
+    /// failures), then run the health check.
+    fn launch_on_port(
+        &mut self,
+        app_handle: &tauri::AppHandle,
+        app_data_dir: &Path,
+        venv_dir: &Path,
+        python_exe: &Path,
+        port: u16,
+    ) -> Result<u16, PortAttemptError> {
+        emit_progress(Some(app_handle), "backend-starting", &format!("Starting backend on port {}", port), 92);
 
         // Launch: python -m suzent.server
-        let mut command = Command::new(&python_exe);
+        let mut command = Command::new(python_exe);
         command.args(["-m", "suzent.server"])
-            .env("VIRTUAL_ENV", &venv_dir)
-            .env("SUZENT_PORT", "0")
+            .env("VIRTUAL_ENV", venv_dir)
+            .env("SUZENT_PORT", port.to_string())
             .env("SUZENT_HOST", "127.0.0.1")
-            .env("SUZENT_APP_DATA", &app_data_dir)
+            .env("SUZENT_APP_DATA", app_data_dir)
             .env("CHATS_DB_PATH", app_data_dir.join("chats.db"))
             .env("LANCEDB_URI", app_data_dir.join("memory"))
             .env("SANDBOX_DATA_PATH", app_data_dir.join("sandbox-data"))
@@ -74,14 +213,15 @@ impl BackendProcess {
             command.creation_flags(0x08000000);
         }
 
-        let mut child = command.spawn()
-            .map_err(|e| format!("Failed to start Python backend: {}", e))?;
+        let child = SharedChild::spawn(&mut command)
+            .map_err(|e| BackendError::io("Failed to start Python backend", e))?;
 
         // Read stdout in a thread to extract the port
-        let stdout = child.stdout.take()
-            .ok_or("Failed to capture stdout")?;
-        let stderr = child.stderr.take()
-            .ok_or("Failed to capture stderr")?;
+        let stdout = child.take_stdout()
+            .ok_or_else(|| BackendError::Other("Failed to capture backend stdout".to_string()))?;
+        let stderr = child.take_stderr()
+            .ok_or_else(|| BackendError::Other("Failed to capture backend stderr".to_string()))?;
+        let child = Arc::new(child);
 
         let (tx, rx_port) = std::sync::mpsc::channel();
 
@@ -118,34 +258,66 @@ impl BackendProcess {
             }
         });
 
-        self.child = Some(child);
-
-        // Wait for the port (timeout 60s — first-run may be slow)
-        match rx_port.recv_timeout(Duration::from_secs(60)) {
-            Ok(port) => {
-                self.port = port;
-                println!("Backend reported port: {}", port);
-                self.wait_for_backend()?;
-                Ok(port)
-            }
-            Err(_) => {
-                self.stop();
-                Err("Timed out waiting for backend to report port".to_string())
+        self.child = Some(child.clone());
+
+        // Wait for the backend to report its port, or die trying (most
+        // likely because `port` lost a race with another process).
+        let deadline = Instant::now() + PORT_WAIT_TIMEOUT;
+        let reported_port = loop {
+            match rx_port.recv_timeout(Duration::from_millis(200)) {
+                Ok(p) => break p,
+                Err(err) => {
+                    if err == RecvTimeoutError::Disconnected {
+                        // stdout closed without a port, which means the child
+                        // is exiting; pause so this doesn't spin until
+                        // try_wait sees the exit.
+                        thread::sleep(Duration::from_millis(200));
+                    }
+                    if let Ok(Some(status)) = child.try_wait() {
+                        // The backend died before reporting a port — most
+                        // likely it lost the bind race for `port` between our
+                        // free-port probe and its own listen() call.
+                        println!("  Backend exited early (code {:?}) trying to bind {}", status.code(), port);
+                        self.child = None;
+                        return Err(PortAttemptError::PortConflict);
+                    }
+                    if Instant::now() >= deadline {
+                        self.stop();
+                        return Err(PortAttemptError::Other(BackendError::PortTimeout));
+                    }
+                }
             }
-        }
+        };
+
+        self.port = reported_port;
+        println!("Backend reported port: {}", reported_port);
+        self.wait_for_backend(app_handle)?;
+        emit_progress(Some(app_handle), "ready", "Backend ready", 100);
+        Ok(reported_port)
     }
 
-    /// Poll the backend health endpoint until it responds or timeout.
-    fn wait_for_backend(&self) -> Result<(), String> {
+    /// Poll the backend health endpoint until it responds, dies, or times
+    /// out, emitting incremental `backend-starting` events instead of a
+    /// single opaque wait.
+    fn wait_for_backend(&self, app_handle: &tauri::AppHandle) -> Result<(), PortAttemptError> {
         let url = format!("http://127.0.0.1:{}/config", self.port);
         let client = reqwest::blocking::Client::builder()
             .timeout(Duration::from_secs(2))
             .build()
-            .map_err(|e| format!("Failed to create HTTP client: {}", e))?;
+            .map_err(|e| PortAttemptError::Other(BackendError::network("Failed to create HTTP client", e)))?;
+
+        let Some(child) = self.child.clone() else {
+            return Err(PortAttemptError::Other(BackendError::ProcessCrashed { exit_code: None }));
+        };
 
-        // 30 attempts * 500ms = 15 seconds timeout
-        for attempt in 1..=30 {
-            thread::sleep(Duration::from_millis(500));
+        for attempt in 1..=HEALTH_CHECK_ATTEMPTS {
+            emit_progress(
+                Some(app_handle),
+                "backend-starting",
+                &format!("Waiting for backend to become ready ({}/{})", attempt, HEALTH_CHECK_ATTEMPTS),
+                95,
+            );
+            thread::sleep(HEALTH_CHECK_INTERVAL);
 
             if let Ok(resp) = client.get(&url).send() {
                 if resp.status().is_success() || resp.status().as_u16() == 404 {
@@ -153,17 +325,66 @@ impl BackendProcess {
                     return Ok(());
                 }
             }
+
+            if let Ok(Some(status)) = child.try_wait() {
+                return Err(PortAttemptError::Other(BackendError::ProcessCrashed { exit_code: status.code() }));
+            }
         }
 
-        Err("Backend failed to respond to health check within 15 seconds".to_string())
+        Err(PortAttemptError::Other(BackendError::HealthCheck))
+    }
+
+    /// Path to this instance's venv Python interpreter. Lets out-of-process
+    /// callers (the IPC server) run `-m suzent.cli` against the same venv
+    /// `start` set up, without going through `spawn_server`.
+    pub fn python_exe(&self, app_data_dir: &Path) -> PathBuf {
+        get_venv_python(&venv_dir_for(app_data_dir, self.python_version.as_deref()))
     }
 
-    /// Stop the backend process gracefully.
+    /// Stop the backend process, preferring a graceful shutdown.
     pub fn stop(&mut self) {
-        if let Some(mut child) = self.child.take() {
-            let _ = child.kill();
-            let _ = child.wait();
+        self.stop_graceful(GRACEFUL_SHUTDOWN_TIMEOUT);
+    }
+
+    /// Ask the backend to shut down cleanly — SIGTERM on Unix, a shutdown
+    /// HTTP request on Windows — and wait up to `timeout` for it to exit
+    /// before falling back to a hard kill. This gives LanceDB/SQLite
+    /// (`chats.db`, `memory/`) a chance to finish any in-flight write.
+    pub fn stop_graceful(&mut self, timeout: Duration) {
+        let Some(child) = self.child.take() else { return };
+
+        #[cfg(unix)]
+        {
+            unsafe {
+                libc::kill(child.id() as libc::pid_t, libc::SIGTERM);
+            }
+        }
+
+        #[cfg(windows)]
+        {
+            let url = format!("http://127.0.0.1:{}/shutdown", self.port);
+            if let Ok(client) = reqwest::blocking::Client::builder()
+                .timeout(Duration::from_secs(2))
+                .build()
+            {
+                let _ = client.post(&url).send();
+            }
         }
+
+        let deadline = Instant::now() + timeout;
+        loop {
+            match child.try_wait() {
+                Ok(Some(_)) => return,
+                Ok(None) if Instant::now() < deadline => {
+                    thread::sleep(Duration::from_millis(100));
+                }
+                _ => break,
+            }
+        }
+
+        println!("Backend did not exit within {:?}, forcing kill", timeout);
+        let _ = child.kill();
+        let _ = child.wait();
     }
 }
 
@@ -184,17 +405,127 @@ fn get_venv_python(venv_dir: &Path) -> PathBuf {
     }
 }
 
+/// The venv directory for a given (optional) pinned Python version. `None`
+/// keeps the original single-venv layout; a pinned version gets its own
+/// venv so switching versions doesn't clobber another one's packages.
+fn venv_dir_for(app_data_dir: &Path, python_version: Option<&str>) -> PathBuf {
+    match python_version {
+        Some(version) => app_data_dir.join(format!("backend-venv-{}", version)),
+        None => app_data_dir.join("backend-venv"),
+    }
+}
+
+/// Timeouts for each phase of the setup pipeline. A stalled download or a
+/// locked venv would otherwise wedge first-run startup indefinitely with no
+/// signal to the user.
+const VENV_CREATE_TIMEOUT: Duration = Duration::from_secs(120);
+const WHEEL_INSTALL_TIMEOUT: Duration = Duration::from_secs(180);
+const PYTHON_INSTALL_TIMEOUT: Duration = Duration::from_secs(300);
+// Chromium is a large download on a slow connection, so it gets more room
+// than the other steps before we give up on it.
+const CHROMIUM_INSTALL_TIMEOUT: Duration = Duration::from_secs(900);
+
+/// Run a child process, forwarding its stdout/stderr lines as
+/// `backend-setup-progress` events under `stage`, and killing it (returning
+/// `BackendError::Timeout`) if it runs longer than `timeout`.
+fn run_with_progress(
+    mut cmd: Command,
+    app_handle: Option<&tauri::AppHandle>,
+    stage: &str,
+    timeout: Duration,
+) -> Result<std::process::ExitStatus, BackendError> {
+    cmd.stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+
+    #[cfg(windows)]
+    cmd.creation_flags(0x08000000);
+
+    let mut child = cmd.spawn()
+        .map_err(|e| BackendError::io(format!("Failed to spawn {}", stage), e))?;
+
+    let stdout = child.stdout.take();
+    let stderr = child.stderr.take();
+    let app_handle = app_handle.cloned();
+
+    let stdout_handle = stdout.map(|out| {
+        let stage = stage.to_string();
+        let app_handle = app_handle.clone();
+        thread::spawn(move || {
+            let reader = BufReader::new(out);
+            for line in reader.lines().flatten() {
+                println!("  {}", line);
+                emit_progress(app_handle.as_ref(), &stage, &line, 0);
+            }
+        })
+    });
+
+    let stderr_handle = stderr.map(|err| {
+        let stage = stage.to_string();
+        let app_handle = app_handle.clone();
+        thread::spawn(move || {
+            let reader = BufReader::new(err);
+            for line in reader.lines().flatten() {
+                println!("  {}", line);
+                emit_progress(app_handle.as_ref(), &stage, &line, 0);
+            }
+        })
+    });
+
+    let deadline = Instant::now() + timeout;
+    let status = loop {
+        match child.try_wait() {
+            Ok(Some(status)) => break Ok(status),
+            Ok(None) => {
+                if Instant::now() >= deadline {
+                    println!("  {} exceeded its {:?} budget, killing...", stage, timeout);
+                    let _ = child.kill();
+                    let _ = child.wait();
+                    break Err(BackendError::Timeout {
+                        command: stage.to_string(),
+                        timeout_secs: timeout.as_secs(),
+                    });
+                }
+                thread::sleep(Duration::from_millis(200));
+            }
+            Err(e) => break Err(BackendError::io(format!("Failed to poll {}", stage), e)),
+        }
+    };
+
+    if let Some(h) = stdout_handle {
+        let _ = h.join();
+    }
+    if let Some(h) = stderr_handle {
+        let _ = h.join();
+    }
+
+    status
+}
+
 /// Ensure the backend venv exists and is up-to-date.
 /// On first run (or version change), creates a venv and installs the suzent wheel.
-pub fn ensure_backend_setup(resource_dir: &Path, app_data_dir: &Path) -> Result<(), String> {
-    let venv_dir = app_data_dir.join("backend-venv");
+/// `app_handle` is `Some` when called from the running GUI (so progress can be
+/// streamed to the frontend) and `None` when called from the CLI bootstrap path.
+/// `python_version` pins a specific interpreter (e.g. "3.11"); `None` uses the
+/// single bundled interpreter, matching the original behavior.
+pub fn ensure_backend_setup(
+    resource_dir: &Path,
+    app_data_dir: &Path,
+    app_handle: Option<&tauri::AppHandle>,
+    python_version: Option<&str>,
+) -> Result<(), BackendError> {
+    let venv_dir = venv_dir_for(app_data_dir, python_version);
     let marker = venv_dir.join(".suzent-version");
 
     let current_version = env!("CARGO_PKG_VERSION");
+    // The marker records both the suzent release and the interpreter version
+    // it was built against, so pinning a different Python forces a rebuild
+    // even if the suzent version hasn't changed.
+    let marker_contents = format!("{}\n{}", current_version, python_version.unwrap_or("bundled"));
 
     let needs_setup = if marker.exists() {
         let stored = std::fs::read_to_string(&marker).unwrap_or_default();
-        if stored.trim() != current_version {
+        if stored.trim() != marker_contents {
             true
         } else {
             // Version matches, but let's verify integrity (specifically CLI entry point)
@@ -209,12 +540,12 @@ pub fn ensure_backend_setup(resource_dir: &Path, app_data_dir: &Path) -> Result<
                    .stdout(Stdio::null())
                    .stderr(Stdio::null())
                    .stdin(Stdio::null());
-                   
+
                 #[cfg(windows)]
                 cmd.creation_flags(0x08000000);
-                
+
                 let status = cmd.status();
-                
+
                 match status {
                     Ok(s) if s.success() => false,
                     _ => {
@@ -234,11 +565,13 @@ pub fn ensure_backend_setup(resource_dir: &Path, app_data_dir: &Path) -> Result<
     }
 
     println!("Setting up backend venv (v{})...", current_version);
+    emit_progress(app_handle, "starting", &format!("Setting up backend (v{})", current_version), 0);
 
     // Locate uv binary
     let uv_exe = find_uv(resource_dir)?;
-    // Locate bundled Python
-    let bundled_python = find_bundled_python(resource_dir)?;
+    // Resolve the interpreter to build the venv from: the single bundled
+    // Python, or (if pinned) an on-demand-installed version.
+    let bundled_python = resolve_python(resource_dir, app_data_dir, &uv_exe, python_version, app_handle)?;
 
     // Check if python is locked before trying to recreate venv to avoid corruption
     let venv_python = get_venv_python(&venv_dir);
@@ -253,20 +586,14 @@ pub fn ensure_backend_setup(resource_dir: &Path, app_data_dir: &Path) -> Result<
 
     // Step 1: Create venv
     println!("  Creating venv at {:?}...", venv_dir);
+    emit_progress(app_handle, "creating-venv", "Creating Python virtual environment", 10);
     let mut cmd = Command::new(&uv_exe);
-    cmd.args(["venv", &venv_dir.to_string_lossy(), "--python", &bundled_python.to_string_lossy()])
-       .stdin(Stdio::null())
-       .stdout(Stdio::null())
-       .stderr(Stdio::null());
-    
-    #[cfg(windows)]
-    cmd.creation_flags(0x08000000);
+    cmd.args(["venv", &venv_dir.to_string_lossy(), "--python", &bundled_python.to_string_lossy()]);
 
-    let status = cmd.status()
-        .map_err(|e| format!("Failed to run uv venv: {}", e))?;
+    let status = run_with_progress(cmd, app_handle, "creating-venv", VENV_CREATE_TIMEOUT)?;
 
     if !status.success() {
-        return Err("uv venv creation failed".to_string());
+        return Err(BackendError::VenvCreationFailed);
     }
 
     // Step 2: Find the wheel
@@ -279,63 +606,61 @@ pub fn ensure_backend_setup(resource_dir: &Path, app_data_dir: &Path) -> Result<
     // Step 3: Install the wheel into the venv
     let venv_python = get_venv_python(&venv_dir);
     println!("  Installing suzent wheel...");
+    emit_progress(app_handle, "installing-wheel", "Installing suzent package", 40);
     let mut cmd = Command::new(&uv_exe);
     cmd.args([
             "pip", "install",
             &wheel_path.to_string_lossy(),
             "--python", &venv_python.to_string_lossy(),
             "--force-reinstall",
-        ])
-        .stdin(Stdio::null())
-        .stdout(Stdio::null())
-        .stderr(Stdio::null());
-        
-    #[cfg(windows)]
-    cmd.creation_flags(0x08000000);
+        ]);
 
-    let status = cmd.status()
-        .map_err(|e| format!("Failed to run uv pip install: {}", e))?;
+    let status = run_with_progress(cmd, app_handle, "installing-wheel", WHEEL_INSTALL_TIMEOUT)?;
 
     if !status.success() {
-        return Err("uv pip install failed".to_string());
+        return Err(BackendError::PipInstall { wheel: wheel_path });
     }
 
     // Step 4: Install Playwright Chromium browser
     println!("  Installing Playwright Chromium (this may take a few minutes)...");
+    emit_progress(app_handle, "installing-chromium", "Downloading Playwright Chromium (this may take a few minutes)", 60);
     let mut cmd = Command::new(&venv_python);
-    cmd.args(["-m", "playwright", "install", "chromium"])
-       .stdin(Stdio::null())
-       .stdout(Stdio::null())
-       .stderr(Stdio::null());
-       
-    #[cfg(windows)]
-    cmd.creation_flags(0x08000000);
+    cmd.args(["-m", "playwright", "install", "chromium"]);
 
-    let playwright_status = cmd.status();
+    let playwright_status = run_with_progress(cmd, app_handle, "installing-chromium", CHROMIUM_INSTALL_TIMEOUT);
 
     match playwright_status {
         Ok(status) if status.success() => {
             println!("  Playwright Chromium installed successfully.");
         }
         Ok(status) => {
-            // Non-fatal: browsing tool will retry on first use
+            // Non-fatal: browsing tool will retry on first use. Surface it as
+            // a structured, retriable error anyway so the frontend can offer
+            // that retry instead of the failure being silently swallowed.
             println!("  WARNING: Playwright install exited with code {:?} (will retry on first use)", status.code());
+            emit_setup_warning(app_handle, BackendError::PlaywrightInstall { retriable: true });
+        }
+        Err(BackendError::Timeout { .. }) => {
+            println!("  WARNING: Playwright install timed out after {:?} (will retry on first use)", CHROMIUM_INSTALL_TIMEOUT);
+            emit_setup_warning(app_handle, BackendError::PlaywrightInstall { retriable: true });
         }
         Err(e) => {
             println!("  WARNING: Failed to run playwright install: {} (will retry on first use)", e);
+            emit_setup_warning(app_handle, BackendError::PlaywrightInstall { retriable: true });
         }
     }
 
     // Write version marker
-    std::fs::write(&marker, current_version)
-        .map_err(|e| format!("Failed to write version marker: {}", e))?;
+    std::fs::write(&marker, marker_contents)
+        .map_err(|e| BackendError::io(format!("Failed to write version marker {:?}", marker), e))?;
 
     println!("  Backend setup complete!");
+    emit_progress(app_handle, "setup-complete", "Backend setup complete", 90);
     Ok(())
 }
 
 /// Find the uv binary inside the resource directory.
-fn find_uv(resource_dir: &Path) -> Result<PathBuf, String> {
+fn find_uv(resource_dir: &Path) -> Result<PathBuf, BackendError> {
     let exe_name = if cfg!(windows) { "uv.exe" } else { "uv" };
 
     // Check directly in resources/
@@ -350,11 +675,11 @@ fn find_uv(resource_dir: &Path) -> Result<PathBuf, String> {
         return Ok(nested);
     }
 
-    Err(format!("uv binary not found in {:?}", resource_dir))
+    Err(BackendError::UvNotFound(resource_dir.to_path_buf()))
 }
 
 /// Find the bundled Python executable.
-fn find_bundled_python(resource_dir: &Path) -> Result<PathBuf, String> {
+fn find_bundled_python(resource_dir: &Path) -> Result<PathBuf, BackendError> {
     let candidates = if cfg!(windows) {
         vec![
             resource_dir.join("resources").join("python").join("python.exe"),
@@ -375,16 +700,85 @@ fn find_bundled_python(resource_dir: &Path) -> Result<PathBuf, String> {
         }
     }
 
-    Err(format!("Bundled Python not found in {:?}", resource_dir))
+    Err(BackendError::PythonNotFound(resource_dir.to_path_buf()))
+}
+
+/// Resolve the interpreter to build the venv from. With no requested version
+/// this is just the bundled interpreter (original behavior). With a
+/// requested version, ask `uv` where it already put one under
+/// `app_data_dir/python/<version>`, or install one there on demand via
+/// `uv python install`.
+fn resolve_python(
+    resource_dir: &Path,
+    app_data_dir: &Path,
+    uv_exe: &Path,
+    requested_version: Option<&str>,
+    app_handle: Option<&tauri::AppHandle>,
+) -> Result<PathBuf, BackendError> {
+    let Some(version) = requested_version else {
+        return find_bundled_python(resource_dir);
+    };
+
+    let install_dir = app_data_dir.join("python").join(version);
+    std::fs::create_dir_all(&install_dir)
+        .map_err(|e| BackendError::io(format!("Failed to create {:?}", install_dir), e))?;
+
+    if let Some(interpreter) = find_managed_python(uv_exe, &install_dir, version) {
+        return Ok(interpreter);
+    }
+
+    println!("  Python {} not found, installing via uv...", version);
+    emit_progress(app_handle, "installing-python", &format!("Installing Python {}", version), 5);
+
+    let mut cmd = Command::new(uv_exe);
+    cmd.args(["python", "install", version, "--install-dir", &install_dir.to_string_lossy()]);
+    let status = run_with_progress(cmd, app_handle, "installing-python", PYTHON_INSTALL_TIMEOUT)?;
+
+    if !status.success() {
+        return Err(BackendError::PythonNotFound(install_dir));
+    }
+
+    find_managed_python(uv_exe, &install_dir, version)
+        .ok_or(BackendError::PythonNotFound(install_dir))
+}
+
+/// Ask `uv` where it placed (or would place) the interpreter for `version`
+/// under `install_dir` via `uv python find`. `UV_PYTHON_INSTALL_DIR` alone
+/// only tells `uv` where to *install*; `python find` still falls back to
+/// system/PATH interpreters matching `version`, which would silently defeat
+/// the per-version isolation this is supposed to guarantee. Passing
+/// `--python-preference only-managed` is what actually restricts the search
+/// to interpreters `uv` itself manages under `install_dir`. We also verify
+/// the result is physically inside `install_dir`, since "managed" still
+/// isn't "managed under *this* install dir" on its own.
+fn find_managed_python(uv_exe: &Path, install_dir: &Path, version: &str) -> Option<PathBuf> {
+    let output = Command::new(uv_exe)
+        .args(["python", "find", version, "--python-preference", "only-managed"])
+        .env("UV_PYTHON_INSTALL_DIR", install_dir)
+        .output()
+        .ok()?;
+
+    if !output.status.success() {
+        return None;
+    }
+
+    let path = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    if path.is_empty() {
+        return None;
+    }
+
+    let path = std::fs::canonicalize(PathBuf::from(path)).ok()?;
+    let install_dir = std::fs::canonicalize(install_dir).ok()?;
+    path.starts_with(&install_dir).then_some(path)
 }
 
 /// Find a .whl file in the given directory.
-fn find_wheel(dir: &Path) -> Result<PathBuf, String> {
+fn find_wheel(dir: &Path) -> Result<PathBuf, BackendError> {
     if !dir.exists() {
-        return Err(format!("Wheel directory not found: {:?}", dir));
+        return Err(BackendError::WheelMissing(dir.to_path_buf()));
     }
 
-    for entry in std::fs::read_dir(dir).map_err(|e| format!("Failed to read dir: {}", e))? {
+    for entry in std::fs::read_dir(dir).map_err(|e| BackendError::io(format!("Failed to read dir {:?}", dir), e))? {
         if let Ok(entry) = entry {
             let path = entry.path();
             if path.extension().is_some_and(|ext| ext == "whl") {
@@ -393,11 +787,17 @@ fn find_wheel(dir: &Path) -> Result<PathBuf, String> {
         }
     }
 
-    Err(format!("No .whl file found in {:?}", dir))
+    Err(BackendError::WheelMissing(dir.to_path_buf()))
 }
 
 /// Sync config and skills from bundled resources to app data dir.
-pub fn sync_app_data(resource_dir: &Path, app_data_dir: &Path) -> Result<(), String> {
+pub fn sync_app_data(
+    resource_dir: &Path,
+    app_data_dir: &Path,
+    app_handle: Option<&tauri::AppHandle>,
+) -> Result<(), BackendError> {
+    emit_progress(app_handle, "syncing-config", "Syncing config and skills", 92);
+
     // Try both direct and nested resource paths
     let prefixes = [
         resource_dir.join("resources"),
@@ -429,11 +829,11 @@ pub fn sync_app_data(resource_dir: &Path, app_data_dir: &Path) -> Result<(), Str
             // First install: copy everything, renaming .example. files
             println!("  Initializing {} directory...", dir_name);
             copy_dir_recursive(&src_dir, &dest_dir, true)
-                .map_err(|e| format!("Failed to copy {}: {}", dir_name, e))?;
+                .map_err(|e| BackendError::io(format!("Failed to copy {}", dir_name), e))?;
         } else {
             // Subsequent runs: only copy missing files
             copy_missing_files(&src_dir, &dest_dir)
-                .map_err(|e| format!("Failed to sync {}: {}", dir_name, e))?;
+                .map_err(|e| BackendError::io(format!("Failed to sync {}", dir_name), e))?;
         }
     }
 
@@ -512,42 +912,102 @@ fn copy_missing_files(src: &Path, dest: &Path) -> std::io::Result<()> {
     Ok(())
 }
 
-/// Generate a CLI shim script in app_data_dir/bin.
-fn ensure_cli_shim(app_data_dir: &Path, python_exe: &Path) -> Result<(), String> {
+/// Generate a CLI shim script in app_data_dir/bin. `python_version`, if set,
+/// is the interpreter version this install was pinned to — the shim embeds
+/// it as the default, but still recognizes a leading `+<version>` argument
+/// (e.g. `suzent +3.11 run`) and routes to that version's venv if one has
+/// been set up, falling back to the default interpreter otherwise.
+fn ensure_cli_shim(app_data_dir: &Path, python_exe: &Path, python_version: Option<&str>) -> Result<(), BackendError> {
     let bin_dir = app_data_dir.join("bin");
     std::fs::create_dir_all(&bin_dir)
-        .map_err(|e| format!("Failed to create bin dir: {}", e))?;
+        .map_err(|e| BackendError::io(format!("Failed to create bin dir {:?}", bin_dir), e))?;
+    let _ = python_version; // embedded only via `python_exe`, which already points at the matching venv
 
     if cfg!(windows) {
         let shim_path = bin_dir.join("suzent.cmd");
         let content = format!(
-            "@echo off\r\n\"{}\" -m suzent.cli %*",
-            python_exe.to_string_lossy()
+            "@echo off\r\n\
+setlocal enabledelayedexpansion\r\n\
+set \"PYTHON_EXE={default_python}\"\r\n\
+if \"%~1\"==\"\" goto run\r\n\
+set \"FIRST=%~1\"\r\n\
+if \"!FIRST:~0,1!\"==\"+\" (\r\n\
+  set \"VER=!FIRST:~1!\"\r\n\
+  set \"VENV_PYTHON={app_data}\\backend-venv-!VER!\\Scripts\\python.exe\"\r\n\
+  if exist \"!VENV_PYTHON!\" set \"PYTHON_EXE=!VENV_PYTHON!\"\r\n\
+  shift\r\n\
+)\r\n\
+\r\n\
+:run\r\n\
+set \"ARGS=\"\r\n\
+:collect\r\n\
+if \"%~1\"==\"\" goto invoke\r\n\
+set \"ARGS=!ARGS! %1\"\r\n\
+shift\r\n\
+goto collect\r\n\
+\r\n\
+:invoke\r\n\
+\"%PYTHON_EXE%\" -m suzent.cli%ARGS%\r\n",
+            default_python = python_exe.to_string_lossy(),
+            app_data = app_data_dir.to_string_lossy(),
         );
         std::fs::write(&shim_path, content)
-            .map_err(|e| format!("Failed to write shim: {}", e))?;
+            .map_err(|e| BackendError::io(format!("Failed to write shim {:?}", shim_path), e))?;
     } else {
         // macOS/Linux
         let shim_path = bin_dir.join("suzent");
         let content = format!(
-            "#!/bin/sh\nexec \"{}\" -m suzent.cli \"$@\"",
-            python_exe.to_string_lossy()
+            "#!/bin/sh\n\
+PYTHON_EXE=\"{default_python}\"\n\
+case \"$1\" in\n\
+  +*)\n\
+    VER=\"${{1#+}}\"\n\
+    VENV_PYTHON=\"{app_data}/backend-venv-$VER/bin/python\"\n\
+    if [ -x \"$VENV_PYTHON\" ]; then\n\
+      PYTHON_EXE=\"$VENV_PYTHON\"\n\
+    fi\n\
+    shift\n\
+    ;;\n\
+esac\n\
+exec \"$PYTHON_EXE\" -m suzent.cli \"$@\"\n",
+            default_python = python_exe.to_string_lossy(),
+            app_data = app_data_dir.to_string_lossy(),
         );
         std::fs::write(&shim_path, content)
-            .map_err(|e| format!("Failed to write shim: {}", e))?;
-        
+            .map_err(|e| BackendError::io(format!("Failed to write shim {:?}", shim_path), e))?;
+
         // Make executable using std::os::unix::fs::PermissionsExt (only on unix)
         #[cfg(unix)]
         {
             use std::os::unix::fs::PermissionsExt;
             let mut perms = std::fs::metadata(&shim_path)
-                .map_err(|e| format!("Failed to get shim metadata: {}", e))?
+                .map_err(|e| BackendError::io(format!("Failed to get shim metadata {:?}", shim_path), e))?
                 .permissions();
             perms.set_mode(0o755);
             std::fs::set_permissions(&shim_path, perms)
-                .map_err(|e| format!("Failed to set shim permissions: {}", e))?;
+                .map_err(|e| BackendError::io(format!("Failed to set shim permissions {:?}", shim_path), e))?;
         }
     }
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn venv_dir_for_defaults_to_single_venv() {
+        let app_data_dir = Path::new("/tmp/suzent-test");
+        assert_eq!(venv_dir_for(app_data_dir, None), app_data_dir.join("backend-venv"));
+    }
+
+    #[test]
+    fn venv_dir_for_pinned_version_gets_its_own_venv() {
+        let app_data_dir = Path::new("/tmp/suzent-test");
+        assert_eq!(
+            venv_dir_for(app_data_dir, Some("3.11")),
+            app_data_dir.join("backend-venv-3.11")
+        );
+    }
+}
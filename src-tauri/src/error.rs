@@ -0,0 +1,141 @@
+use std::path::PathBuf;
+
+use serde::Serialize;
+use thiserror::Error;
+
+/// Errors from backend venv setup, process lifecycle, and config sync.
+/// Replaces the old `Result<_, String>` plumbing so callers (and ultimately
+/// the frontend, via `ErrorPayload`) can distinguish failure classes instead
+/// of pattern-matching on a formatted message — e.g. "permission denied on
+/// venv" vs. "wheel not found" vs. "network failure during Playwright
+/// install".
+#[derive(Debug, Error)]
+pub enum BackendError {
+    #[error("failed to resolve app data dir: {0}")]
+    AppDataDir(String),
+
+    #[error("failed to resolve resource dir: {0}")]
+    ResourceDir(String),
+
+    #[error("uv binary not found in {0:?}")]
+    UvNotFound(PathBuf),
+
+    #[error("Python interpreter not found in {0:?}")]
+    PythonNotFound(PathBuf),
+
+    #[error("Python interpreter not found at {0:?} after setup")]
+    VenvPythonMissing(PathBuf),
+
+    #[error("uv venv creation exited with a non-zero status")]
+    VenvCreationFailed,
+
+    #[error("no .whl file found in {0:?}")]
+    WheelMissing(PathBuf),
+
+    #[error("uv pip install failed for {wheel:?}")]
+    PipInstall { wheel: PathBuf },
+
+    #[error("playwright chromium install failed (retriable: {retriable})")]
+    PlaywrightInstall { retriable: bool },
+
+    #[error("command `{command}` timed out after {timeout_secs}s")]
+    Timeout { command: String, timeout_secs: u64 },
+
+    #[error("timed out waiting for backend to report its port")]
+    PortTimeout,
+
+    #[error("could not find a free port after exhausting all attempts")]
+    PortExhausted,
+
+    #[error("backend process exited during startup (code {exit_code:?})")]
+    ProcessCrashed { exit_code: Option<i32> },
+
+    #[error("backend failed health check within the allotted time")]
+    HealthCheck,
+
+    #[error("{context}: {source}")]
+    Io { context: String, source: std::io::Error },
+
+    #[error("{context}: {source}")]
+    Network { context: String, source: reqwest::Error },
+
+    #[error("{0}")]
+    Other(String),
+}
+
+impl BackendError {
+    pub fn io(context: impl Into<String>, source: std::io::Error) -> Self {
+        BackendError::Io { context: context.into(), source }
+    }
+
+    pub fn network(context: impl Into<String>, source: reqwest::Error) -> Self {
+        BackendError::Network { context: context.into(), source }
+    }
+
+    /// Whether the frontend should offer a retry for this failure, as
+    /// opposed to one that needs user intervention (missing files, a
+    /// corrupt install) or a manual reset.
+    pub fn retriable(&self) -> bool {
+        matches!(
+            self,
+            BackendError::PlaywrightInstall { retriable: true }
+                | BackendError::PortTimeout
+                | BackendError::HealthCheck
+                | BackendError::Timeout { .. }
+                | BackendError::Network { .. }
+                | BackendError::PortExhausted
+                | BackendError::ProcessCrashed { .. }
+        )
+    }
+
+    fn kind(&self) -> &'static str {
+        match self {
+            BackendError::AppDataDir(_) => "app_data_dir",
+            BackendError::ResourceDir(_) => "resource_dir",
+            BackendError::UvNotFound(_) => "uv_not_found",
+            BackendError::PythonNotFound(_) => "python_not_found",
+            BackendError::VenvPythonMissing(_) => "venv_python_missing",
+            BackendError::VenvCreationFailed => "venv_creation",
+            BackendError::WheelMissing(_) => "wheel_missing",
+            BackendError::PipInstall { .. } => "pip_install",
+            BackendError::PlaywrightInstall { .. } => "playwright_install",
+            BackendError::Timeout { .. } => "timeout",
+            BackendError::PortTimeout => "port_timeout",
+            BackendError::PortExhausted => "port_exhausted",
+            BackendError::ProcessCrashed { .. } => "process_crashed",
+            BackendError::HealthCheck => "health_check",
+            BackendError::Io { .. } => "io",
+            BackendError::Network { .. } => "network",
+            BackendError::Other(_) => "other",
+        }
+    }
+}
+
+/// Serializable shape of a `BackendError` for the Tauri command boundary —
+/// `thiserror`'s `source` fields (`std::io::Error`, `reqwest::Error`) aren't
+/// themselves `Serialize`, so commands and events send this instead.
+#[derive(Serialize)]
+pub struct ErrorPayload {
+    pub kind: &'static str,
+    pub message: String,
+    pub retriable: bool,
+}
+
+impl From<&BackendError> for ErrorPayload {
+    fn from(err: &BackendError) -> Self {
+        ErrorPayload {
+            kind: err.kind(),
+            message: err.to_string(),
+            retriable: err.retriable(),
+        }
+    }
+}
+
+impl Serialize for BackendError {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        ErrorPayload::from(self).serialize(serializer)
+    }
+}
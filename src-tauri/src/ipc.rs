@@ -0,0 +1,448 @@
+use std::collections::HashMap;
+use std::io::{self, BufRead, BufReader, IsTerminal, Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::thread;
+
+use serde::{Deserialize, Serialize};
+use tauri::Manager;
+
+use crate::AppState;
+
+/// What the CLI branch sends the running GUI instance: the invoking shell's
+/// directory and environment, plus the subcommand arguments, so the
+/// forwarded `suzent.cli` process behaves like a cold-spawned one would.
+/// `stdin` is the client's stdin read eagerly (only when it isn't a TTY —
+/// see `forward_to_running_instance`), so piped input still reaches the
+/// forwarded process even though this is a one-shot request/response
+/// protocol rather than a live interactive stream.
+#[derive(Serialize, Deserialize)]
+pub struct CliRequest {
+    pub cwd: PathBuf,
+    pub args: Vec<String>,
+    pub env: HashMap<String, String>,
+    pub stdin: Vec<u8>,
+}
+
+/// One chunk of the forwarded CLI session, streamed back to the client in
+/// order and terminated by `Exit`. `NotReady` is sent instead when the GUI's
+/// backend hasn't finished starting yet, so the client can fall back to
+/// cold-spawning its own CLI instead of treating "not ready" as a command
+/// failure.
+#[derive(Serialize, Deserialize)]
+pub enum IpcFrame {
+    Stdout(String),
+    Stderr(String),
+    Exit(i32),
+    NotReady,
+}
+
+/// Unix domain socket path for the IPC endpoint. Windows uses a fixed named
+/// pipe instead (see `PIPE_NAME`), since pipes are named globally rather
+/// than scoped to a location on disk.
+fn socket_path(app_data_dir: &Path) -> PathBuf {
+    app_data_dir.join("suzent.sock")
+}
+
+#[cfg(windows)]
+const PIPE_NAME: &str = r"\\.\pipe\com.suzent.app";
+
+/// Write a length-prefixed JSON frame: a 4-byte little-endian length
+/// followed by the JSON payload.
+fn write_frame<W: Write, T: Serialize>(mut w: W, value: &T) -> io::Result<()> {
+    let payload = serde_json::to_vec(value).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    w.write_all(&(payload.len() as u32).to_le_bytes())?;
+    w.write_all(&payload)?;
+    w.flush()
+}
+
+/// Read one length-prefixed JSON frame, or `Ok(None)` on clean EOF.
+fn read_frame<R: Read, T: serde::de::DeserializeOwned>(mut r: R) -> io::Result<Option<T>> {
+    let mut len_buf = [0u8; 4];
+    match r.read_exact(&mut len_buf) {
+        Ok(()) => {}
+        Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => return Ok(None),
+        Err(e) => return Err(e),
+    }
+    let len = u32::from_le_bytes(len_buf) as usize;
+    let mut payload = vec![0u8; len];
+    r.read_exact(&mut payload)?;
+    serde_json::from_slice(&payload)
+        .map(Some)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+/// Start the IPC accept loop in a background thread. CLI invocations connect
+/// here (see `forward_to_running_instance` below) instead of cold-spawning
+/// their own `suzent.cli` process and backend, so they can share the GUI's
+/// already-warm one.
+pub fn start_server(app_handle: tauri::AppHandle, app_data_dir: PathBuf) {
+    thread::spawn(move || {
+        #[cfg(unix)]
+        run_unix_server(&app_handle, &app_data_dir);
+        #[cfg(windows)]
+        run_windows_server(&app_handle);
+    });
+}
+
+#[cfg(unix)]
+fn run_unix_server(app_handle: &tauri::AppHandle, app_data_dir: &Path) {
+    use std::os::unix::net::UnixListener;
+
+    let path = socket_path(app_data_dir);
+    // Remove a stale socket left behind by an unclean shutdown before binding.
+    let _ = std::fs::remove_file(&path);
+
+    let listener = match UnixListener::bind(&path) {
+        Ok(l) => l,
+        Err(e) => {
+            eprintln!("ipc: failed to bind {:?}: {}", path, e);
+            return;
+        }
+    };
+
+    for stream in listener.incoming() {
+        match stream {
+            Ok(stream) => {
+                let app_handle = app_handle.clone();
+                thread::spawn(move || handle_client(stream, app_handle));
+            }
+            Err(e) => eprintln!("ipc: accept failed: {}", e),
+        }
+    }
+}
+
+/// Try to forward a CLI invocation to an already-running GUI instance.
+/// `cwd`/`env` should be the invoking shell's original directory and
+/// environment (captured before `main` forces the process's cwd to the
+/// executable's directory), so relative paths and shell env vars resolve the
+/// same way they would for a cold-spawned process. Returns `Some(exit_code)`
+/// if the forward succeeded (the CLI branch should exit with it), or `None`
+/// if no instance is listening *or* its backend isn't ready yet, so the
+/// caller can fall back to cold-spawning its own `suzent.cli` process.
+pub fn forward_to_running_instance(
+    app_data_dir: &Path,
+    args: &[String],
+    cwd: PathBuf,
+    env: HashMap<String, String>,
+) -> Option<i32> {
+    // Only read our own stdin if something is actually piped into it —
+    // reading an interactive TTY's stdin would block waiting for EOF that
+    // never comes. This means piped input is forwarded; a live interactive
+    // session is not (the forwarded process still gets stdin closed, same
+    // as before).
+    let stdin = if std::io::stdin().is_terminal() {
+        Vec::new()
+    } else {
+        let mut buf = Vec::new();
+        let _ = std::io::stdin().read_to_end(&mut buf);
+        buf
+    };
+
+    let request = CliRequest { cwd, args: args.to_vec(), env, stdin };
+
+    #[cfg(unix)]
+    return forward_unix(app_data_dir, &request);
+    #[cfg(windows)]
+    return forward_windows(&request);
+}
+
+#[cfg(unix)]
+fn forward_unix(app_data_dir: &Path, request: &CliRequest) -> Option<i32> {
+    use std::os::unix::net::UnixStream;
+
+    let mut stream = UnixStream::connect(socket_path(app_data_dir)).ok()?;
+    write_frame(&stream, request).ok()?;
+    stream.flush().ok()?;
+    stream_frames(&mut stream)
+}
+
+/// Handle one connected client: read its `CliRequest`, run `suzent.cli`
+/// against the warm backend venv/port, and stream stdout/stderr/exit back.
+fn handle_client<S: Read + Write>(mut stream: S, app_handle: tauri::AppHandle) {
+    let request: CliRequest = match read_frame(&mut stream) {
+        Ok(Some(req)) => req,
+        Ok(None) => return,
+        Err(e) => {
+            eprintln!("ipc: failed to read request: {}", e);
+            return;
+        }
+    };
+
+    run_and_stream(&mut stream, &request, &app_handle);
+}
+
+/// Drain response frames from an already-connected stream onto our own
+/// stdout/stderr, returning the forwarded process's exit code, or `None` if
+/// the GUI reported its backend isn't ready yet (the caller should fall back
+/// to cold-spawning instead of treating this as a failed command).
+fn stream_frames<S: Read>(stream: &mut S) -> Option<i32> {
+    loop {
+        match read_frame(&mut *stream) {
+            Ok(Some(IpcFrame::Stdout(line))) => println!("{}", line),
+            Ok(Some(IpcFrame::Stderr(line))) => eprintln!("{}", line),
+            Ok(Some(IpcFrame::Exit(code))) => return Some(code),
+            Ok(Some(IpcFrame::NotReady)) => return None,
+            Ok(None) => return Some(1),
+            Err(e) => {
+                eprintln!("suzent: lost connection to running instance: {}", e);
+                return Some(1);
+            }
+        }
+    }
+}
+
+fn run_and_stream<W: Write>(out: &mut W, request: &CliRequest, app_handle: &tauri::AppHandle) {
+    let app_data_dir = match app_handle.path().app_data_dir() {
+        Ok(d) => d,
+        Err(e) => {
+            let _ = write_frame(&mut *out, &IpcFrame::Stderr(format!("suzent: failed to resolve app data dir: {}", e)));
+            let _ = write_frame(out, &IpcFrame::Exit(1));
+            return;
+        }
+    };
+
+    let (python_exe, port) = match app_handle.try_state::<AppState>().and_then(|state| {
+        state.backend.lock().ok().and_then(|guard| {
+            guard.as_ref().map(|b| (b.python_exe(&app_data_dir), b.port))
+        })
+    }) {
+        Some(pair) => pair,
+        None => {
+            let _ = write_frame(out, &IpcFrame::NotReady);
+            return;
+        }
+    };
+
+    let mut cmd = Command::new(python_exe);
+    cmd.arg("-m").arg("suzent.cli")
+        .args(&request.args)
+        .current_dir(&request.cwd)
+        .envs(&request.env)
+        .env("SUZENT_APP_DATA", &app_data_dir)
+        .env("SUZENT_PORT", port.to_string())
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+
+    let mut child = match cmd.spawn() {
+        Ok(c) => c,
+        Err(e) => {
+            let _ = write_frame(&mut *out, &IpcFrame::Stderr(format!("suzent: failed to spawn CLI: {}", e)));
+            let _ = write_frame(out, &IpcFrame::Exit(1));
+            return;
+        }
+    };
+
+    // The client only ever sent us whatever it had already buffered (see
+    // `forward_to_running_instance`), so write it and close stdin right
+    // away — there's no live interactive stream over this protocol.
+    if let Some(mut stdin) = child.stdin.take() {
+        if !request.stdin.is_empty() {
+            let _ = stdin.write_all(&request.stdin);
+        }
+    }
+
+    let stdout = child.stdout.take();
+    let stderr = child.stderr.take();
+
+    // Stream stdout and stderr lines to `out` as they arrive, interleaved in
+    // the order produced, via a channel fed by one reader thread per stream —
+    // rather than buffering all of stderr until stdout hits EOF, which lost
+    // interleaving and could balloon memory for a chatty command.
+    let (tx, rx) = std::sync::mpsc::channel::<IpcFrame>();
+
+    let stdout_handle = stdout.map(|s| {
+        let tx = tx.clone();
+        thread::spawn(move || {
+            for line in BufReader::new(s).lines().flatten() {
+                let _ = tx.send(IpcFrame::Stdout(line));
+            }
+        })
+    });
+    let stderr_handle = stderr.map(|s| {
+        let tx = tx.clone();
+        thread::spawn(move || {
+            for line in BufReader::new(s).lines().flatten() {
+                let _ = tx.send(IpcFrame::Stderr(line));
+            }
+        })
+    });
+    drop(tx);
+
+    for frame in rx {
+        let _ = write_frame(&mut *out, &frame);
+    }
+
+    if let Some(h) = stdout_handle {
+        let _ = h.join();
+    }
+    if let Some(h) = stderr_handle {
+        let _ = h.join();
+    }
+
+    let code = child.wait().ok().and_then(|s| s.code()).unwrap_or(1);
+    let _ = write_frame(out, &IpcFrame::Exit(code));
+}
+
+// --- Windows named pipe transport ---
+//
+// `std` has no named pipe support, so the server and client each wrap a raw
+// pipe `HANDLE` in a small `Read + Write` type backed by `ReadFile`/
+// `WriteFile`, mirroring how the rest of this module is written against
+// generic `Read + Write` streams.
+
+#[cfg(windows)]
+struct NamedPipe(windows_sys::Win32::Foundation::HANDLE);
+
+#[cfg(windows)]
+unsafe impl Send for NamedPipe {}
+
+#[cfg(windows)]
+impl Read for NamedPipe {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        use windows_sys::Win32::Storage::FileSystem::ReadFile;
+        let mut read = 0u32;
+        let ok = unsafe {
+            ReadFile(self.0, buf.as_mut_ptr(), buf.len() as u32, &mut read, std::ptr::null_mut())
+        };
+        if ok == 0 {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(read as usize)
+    }
+}
+
+#[cfg(windows)]
+impl Write for NamedPipe {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        use windows_sys::Win32::Storage::FileSystem::WriteFile;
+        let mut written = 0u32;
+        let ok = unsafe {
+            WriteFile(self.0, buf.as_ptr(), buf.len() as u32, &mut written, std::ptr::null_mut())
+        };
+        if ok == 0 {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(written as usize)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(windows)]
+impl Drop for NamedPipe {
+    fn drop(&mut self) {
+        use windows_sys::Win32::Foundation::CloseHandle;
+        unsafe { CloseHandle(self.0) };
+    }
+}
+
+#[cfg(windows)]
+fn wide_null(s: &str) -> Vec<u16> {
+    use std::os::windows::ffi::OsStrExt;
+    std::ffi::OsStr::new(s).encode_wide().chain(std::iter::once(0)).collect()
+}
+
+#[cfg(windows)]
+fn run_windows_server(app_handle: &tauri::AppHandle) {
+    use windows_sys::Win32::Foundation::INVALID_HANDLE_VALUE;
+    use windows_sys::Win32::System::Pipes::{
+        ConnectNamedPipe, CreateNamedPipeW, PIPE_ACCESS_DUPLEX, PIPE_READMODE_BYTE, PIPE_TYPE_BYTE, PIPE_WAIT,
+    };
+
+    let name = wide_null(PIPE_NAME);
+
+    loop {
+        let handle = unsafe {
+            CreateNamedPipeW(
+                name.as_ptr(),
+                PIPE_ACCESS_DUPLEX,
+                PIPE_TYPE_BYTE | PIPE_READMODE_BYTE | PIPE_WAIT,
+                windows_sys::Win32::System::Pipes::PIPE_UNLIMITED_INSTANCES,
+                4096,
+                4096,
+                0,
+                std::ptr::null_mut(),
+            )
+        };
+
+        if handle == INVALID_HANDLE_VALUE {
+            eprintln!("ipc: failed to create named pipe: {}", io::Error::last_os_error());
+            return;
+        }
+
+        let connected = unsafe { ConnectNamedPipe(handle, std::ptr::null_mut()) };
+        if connected == 0 {
+            let err = io::Error::last_os_error();
+            // ERROR_PIPE_CONNECTED (535): a client connected between
+            // CreateNamedPipeW and ConnectNamedPipe — treat as success.
+            if err.raw_os_error() != Some(535) {
+                eprintln!("ipc: ConnectNamedPipe failed: {}", err);
+                unsafe { windows_sys::Win32::Foundation::CloseHandle(handle) };
+                continue;
+            }
+        }
+
+        let app_handle = app_handle.clone();
+        thread::spawn(move || handle_client(NamedPipe(handle), app_handle));
+    }
+}
+
+#[cfg(windows)]
+fn forward_windows(request: &CliRequest) -> Option<i32> {
+    use windows_sys::Win32::Foundation::{GENERIC_READ, GENERIC_WRITE, INVALID_HANDLE_VALUE};
+    use windows_sys::Win32::Storage::FileSystem::{CreateFileW, OPEN_EXISTING};
+
+    let name = wide_null(PIPE_NAME);
+    let handle = unsafe {
+        CreateFileW(
+            name.as_ptr(),
+            GENERIC_READ | GENERIC_WRITE,
+            0,
+            std::ptr::null_mut(),
+            OPEN_EXISTING,
+            0,
+            std::ptr::null_mut(),
+        )
+    };
+
+    if handle == INVALID_HANDLE_VALUE {
+        return None;
+    }
+
+    let mut pipe = NamedPipe(handle);
+    write_frame(&mut pipe, request).ok()?;
+    stream_frames(&mut pipe)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn write_then_read_frame_round_trips() {
+        let mut buf = Vec::new();
+        write_frame(&mut buf, &IpcFrame::Stdout("hello".to_string())).unwrap();
+        write_frame(&mut buf, &IpcFrame::Exit(7)).unwrap();
+
+        let mut cursor = io::Cursor::new(buf);
+        match read_frame::<_, IpcFrame>(&mut cursor).unwrap() {
+            Some(IpcFrame::Stdout(line)) => assert_eq!(line, "hello"),
+            other => panic!("unexpected frame: {:?}", other.is_some()),
+        }
+        match read_frame::<_, IpcFrame>(&mut cursor).unwrap() {
+            Some(IpcFrame::Exit(code)) => assert_eq!(code, 7),
+            other => panic!("unexpected frame: {:?}", other.is_some()),
+        }
+    }
+
+    #[test]
+    fn read_frame_returns_none_on_clean_eof() {
+        let mut cursor = io::Cursor::new(Vec::new());
+        let frame = read_frame::<_, IpcFrame>(&mut cursor).unwrap();
+        assert!(frame.is_none());
+    }
+}
@@ -2,13 +2,24 @@
 // REMOVED: #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
 mod backend;
+mod error;
+mod ipc;
+mod relaunch;
+mod reset;
+mod terminal;
+mod watcher;
 
 use backend::BackendProcess;
+use error::BackendError;
+use reset::StateRoot;
+use terminal::TermConfig;
+use watcher::BackendWatcher;
 use tauri::{Manager, State, Emitter};
 use std::sync::Mutex;
 
-struct AppState {
-    backend: Mutex<Option<BackendProcess>>,
+pub struct AppState {
+    pub backend: Mutex<Option<BackendProcess>>,
+    watcher: Mutex<Option<BackendWatcher>>,
 }
 
 #[tauri::command]
@@ -23,12 +34,124 @@ fn get_backend_port(state: State<AppState>) -> Result<u16, String> {
     }
 }
 
+/// Open the user's configured terminal running `suzent <subcommand>`, so GUI
+/// users can drop into an interactive CLI session without a separate install.
+#[tauri::command]
+fn launch_cli_terminal(app_handle: tauri::AppHandle, subcommand: Vec<String>) -> Result<(), BackendError> {
+    let app_data_dir = app_handle.path().app_data_dir()
+        .map_err(|e| BackendError::AppDataDir(e.to_string()))?;
+    let config = terminal::load_or_detect_term_config(&app_data_dir);
+    terminal::launch(&app_data_dir, &config, &subcommand)
+}
+
+#[tauri::command]
+fn get_term_config(app_handle: tauri::AppHandle) -> Result<TermConfig, BackendError> {
+    let app_data_dir = app_handle.path().app_data_dir()
+        .map_err(|e| BackendError::AppDataDir(e.to_string()))?;
+    Ok(terminal::load_or_detect_term_config(&app_data_dir))
+}
+
+#[tauri::command]
+fn set_term_config(app_handle: tauri::AppHandle, config: TermConfig) -> Result<(), BackendError> {
+    let app_data_dir = app_handle.path().app_data_dir()
+        .map_err(|e| BackendError::AppDataDir(e.to_string()))?;
+    terminal::save_term_config(&app_data_dir, &config)
+}
+
+/// Report (or, unless `dry_run`, perform) a reset of the backend venv(s).
+/// Stops the running backend before wiping its venv, re-runs setup, then
+/// starts a fresh backend so the GUI keeps working without a manual restart.
+#[tauri::command]
+fn reset_backend(app_handle: tauri::AppHandle, dry_run: bool) -> Result<Vec<StateRoot>, BackendError> {
+    let app_data_dir = app_handle.path().app_data_dir()
+        .map_err(|e| BackendError::AppDataDir(e.to_string()))?;
+    let roots = reset::enumerate_state_roots(&app_data_dir);
+
+    if dry_run {
+        return Ok(roots);
+    }
+
+    let resource_dir = app_handle.path().resource_dir()
+        .map_err(|e| BackendError::ResourceDir(e.to_string()))?;
+
+    let Some(state) = app_handle.try_state::<AppState>() else {
+        return Err(BackendError::Other("App state not initialized".to_string()));
+    };
+    let mut guard = state.backend.lock()
+        .map_err(|e| BackendError::Other(format!("Lock error: {}", e)))?;
+
+    if let Some(backend) = guard.as_mut() {
+        backend.stop();
+    }
+
+    let python_version = std::env::var("SUZENT_PYTHON_VERSION").ok();
+    reset::reset(&resource_dir, &app_data_dir, Some(&app_handle), python_version.as_deref())?;
+
+    let mut backend = BackendProcess::new();
+    let port = backend.start(&app_handle)?;
+    println!("Backend reset and restarted on port {}", port);
+    *guard = Some(backend);
+
+    Ok(roots)
+}
+
+/// Tear down the running backend and re-exec this binary in place, so the
+/// frontend can offer a reliable "Restart Suzent" action. `allow_symlink`
+/// only matters on macOS, where relaunching through a symlinked path is
+/// refused by default (see `relaunch::resolve_relaunch_target`).
+#[tauri::command]
+fn relaunch_app(app_handle: tauri::AppHandle, allow_symlink: bool) -> Result<(), BackendError> {
+    let target = relaunch::resolve_relaunch_target(allow_symlink)?;
+
+    if let Some(state) = app_handle.try_state::<AppState>() {
+        if let Ok(mut guard) = state.backend.lock() {
+            if let Some(backend) = guard.as_mut() {
+                backend.stop();
+            }
+        }
+    }
+
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    Err(relaunch::exec_relaunch(&target, &args))
+}
+
+/// `suzent reset [--dry-run]`: report the known on-disk state roots and their
+/// sizes, and unless `--dry-run` was passed, wipe the venv(s) and rebuild them.
+/// Handled here, before the normal `python -m suzent.cli` dispatch below, so
+/// it works even if the venv is the thing that's broken.
+fn run_cli_reset(exe_dir: &std::path::Path, app_data_dir: &std::path::Path, dry_run: bool, python_version: Option<&str>) {
+    let roots = reset::enumerate_state_roots(app_data_dir);
+    let total: u64 = roots.iter().map(|r| r.size_bytes).sum();
+
+    println!("Backend state on disk (only backend-venv directories are removed):");
+    for root in &roots {
+        println!("  {:>10}  {}", reset::format_size(root.size_bytes), root.path.display());
+    }
+    println!("Total: {}", reset::format_size(total));
+
+    if dry_run {
+        println!("(dry run — nothing was removed)");
+        return;
+    }
+
+    match reset::reset(exe_dir, app_data_dir, None, python_version) {
+        Ok(()) => println!("Backend environment reset. Run any suzent command (or relaunch the app) to pick it up."),
+        Err(e) => eprintln!("Reset failed: {}", e),
+    }
+}
+
 // Minimal logging helper for debugging CLI hangs
 
 
 
 
 fn main() {
+    // Capture the invoking shell's cwd before we force it to the executable's
+    // directory below — the CLI path needs the real one so relative paths
+    // like `suzent process ./data.csv` resolve against where the user ran
+    // the command, not the install directory.
+    let original_cwd = std::env::current_dir().unwrap_or_else(|_| std::path::PathBuf::from("."));
+
     // Force the working directory to the executable's directory.
     // This fixes issues where NSIS installers launch the app with an invalid CWD (like System32 or %TEMP%).
     if let Ok(exe_path) = std::env::current_exe() {
@@ -63,20 +186,49 @@ fn main() {
             let app_data_root = std::path::PathBuf::from(app_data);
             // Default bundle identifier
             let suzent_app_data = app_data_root.join("com.suzent.app");
-            let python_exe = suzent_app_data.join("backend-venv").join("Scripts").join("python.exe");
+
+            if args[1] == "reset" {
+                let dry_run = args.iter().any(|a| a == "--dry-run");
+                let python_version = std::env::var("SUZENT_PYTHON_VERSION").ok();
+                if let Ok(exe_path) = std::env::current_exe() {
+                    if let Some(exe_dir) = exe_path.parent() {
+                        run_cli_reset(exe_dir, &suzent_app_data, dry_run, python_version.as_deref());
+                    }
+                }
+                std::process::exit(0);
+            }
+
+            // If a GUI instance is already running, forward the command to it
+            // over the IPC socket instead of paying for venv validation and a
+            // fresh process every time.
+            if let Some(code) = ipc::forward_to_running_instance(
+                &suzent_app_data,
+                &args[1..],
+                original_cwd.clone(),
+                std::env::vars().collect(),
+            ) {
+                std::process::exit(code);
+            }
+
+            let python_version = std::env::var("SUZENT_PYTHON_VERSION").ok();
+            let venv_dir_name = match &python_version {
+                Some(v) => format!("backend-venv-{}", v),
+                None => "backend-venv".to_string(),
+            };
+            let python_exe = suzent_app_data.join(venv_dir_name).join("Scripts").join("python.exe");
 
             // Always attempt to validate/setup the environment first
             // This ensures integrity checks (like missing entry points) are run
             if let Ok(exe_path) = std::env::current_exe() {
                  if let Some(exe_dir) = exe_path.parent() {
                     // suzent_app_data is already defined above as Local/com.suzent.app
-                    
+
                     // We use the exe directory as the resource directory
-                    if let Err(e) = backend::ensure_backend_setup(exe_dir, &suzent_app_data) {
+                    if let Err(e) = backend::ensure_backend_setup(exe_dir, &suzent_app_data, None, python_version.as_deref()) {
                         eprintln!("Warning: Environment setup failed: {}", e);
                     }
-                    
-                    if let Err(e) = backend::sync_app_data(exe_dir, &suzent_app_data) {
+
+                    if let Err(e) = backend::sync_app_data(exe_dir, &suzent_app_data, None) {
                          eprintln!("Warning: Failed to sync app data: {}", e);
                     }
                  }
@@ -89,6 +241,8 @@ fn main() {
                 
                 let status = std::process::Command::new(python_exe)
                     .args(["-m", "suzent.cli"])
+                    .current_dir(&original_cwd)
+                    .envs(std::env::vars())
                     .env("SUZENT_APP_DATA", &suzent_app_data)
                     .stdin(std::process::Stdio::null())
                     .args(cli_args)
@@ -124,11 +278,19 @@ fn main() {
             // Initialize AppState with no backend yet
             app.manage(AppState {
                 backend: Mutex::new(None),
+                watcher: Mutex::new(None),
             });
 
             // Clone handle for the thread
             let app_handle = app.handle().clone();
 
+            // Accept CLI invocations forwarded from cold-spawned instances of
+            // this binary so they can share this instance's warm backend.
+            if let Ok(app_data_dir) = app_handle.path().app_data_dir() {
+                std::fs::create_dir_all(&app_data_dir).ok();
+                ipc::start_server(app_handle.clone(), app_data_dir);
+            }
+
             // Start backend in a separate thread so we don't block the UI
             std::thread::spawn(move || {
                 // Determine port and backend process based on build mode
@@ -157,18 +319,38 @@ try {{ localStorage.setItem('SUZENT_PORT', '{port}'); }} catch (e) {{}}
                             eprintln!("Failed to inject backend port: {}", e);
                             let _ = window.emit("backend-error", format!("Failed to inject backend port: {}", e));
                         }
+
+                        // Watch skills/config for changes and restart the backend when they settle.
+                        // Only meaningful once we actually own the child process (release builds).
+                        #[cfg(not(debug_assertions))]
+                        if let Ok(app_data_dir) = app_handle.path().app_data_dir() {
+                            let new_watcher = BackendWatcher::start(app_handle.clone(), app_data_dir);
+                            if let Some(state) = app_handle.try_state::<AppState>() {
+                                if let Ok(mut guard) = state.watcher.lock() {
+                                    *guard = Some(new_watcher);
+                                }
+                            }
+                        }
                     }
                     Err(e) => {
                         eprintln!("Failed to start backend: {}", e);
-                        // Maybe show error in UI?
-                        let _ = window.emit("backend-error", e);
+                        // Serialize the structured error (kind + message + retriable)
+                        // so the frontend can show an actionable message.
+                        let _ = window.emit("backend-error", &e);
                     }
                 }
             });
 
             Ok(())
         })
-        .invoke_handler(tauri::generate_handler![get_backend_port])
+        .invoke_handler(tauri::generate_handler![
+            get_backend_port,
+            launch_cli_terminal,
+            get_term_config,
+            set_term_config,
+            reset_backend,
+            relaunch_app
+        ])
         .plugin(tauri_plugin_shell::init())
         .plugin(tauri_plugin_dialog::init())
         .plugin(tauri_plugin_fs::init())
@@ -180,14 +362,14 @@ try {{ localStorage.setItem('SUZENT_PORT', '{port}'); }} catch (e) {{}}
 /// - Release: Starts bundled backend and returns its dynamically allocated port
 /// - Debug: Returns default port 25314 (expects manually-run backend)
 #[cfg(not(debug_assertions))]
-fn get_backend_config(app: &tauri::AppHandle) -> Result<(u16, BackendProcess), String> {
+fn get_backend_config(app: &tauri::AppHandle) -> Result<(u16, BackendProcess), BackendError> {
     let mut backend = BackendProcess::new();
     let port = backend.start(app)?;
     Ok((port, backend))
 }
 
 #[cfg(debug_assertions)]
-fn get_backend_config(_app: &tauri::AppHandle) -> Result<(u16, BackendProcess), String> {
+fn get_backend_config(_app: &tauri::AppHandle) -> Result<(u16, BackendProcess), BackendError> {
     let port = std::env::var("SUZENT_PORT")
         .unwrap_or_else(|_| "25314".to_string())
         .parse::<u16>()
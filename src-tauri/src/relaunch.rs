@@ -0,0 +1,61 @@
+use std::path::{Path, PathBuf};
+
+use crate::error::BackendError;
+
+/// Resolve the path to re-exec as "this binary", rather than trusting
+/// `current_exe()` blindly:
+/// - Linux: prefer the `APPIMAGE` env var if set. `current_exe()` resolves to
+///   the binary extracted to the AppImage's temporary mountpoint, which is
+///   torn down once this process exits — re-execing that path fails.
+/// - macOS: refuse to relaunch through a symlinked path (the documented
+///   macOS app-bundle symlink hazard, where relative resource lookups break
+///   when launched via a symlink) unless `allow_symlink` overrides it.
+/// - Windows: `current_exe()` is reliable as-is.
+pub fn resolve_relaunch_target(allow_symlink: bool) -> Result<PathBuf, BackendError> {
+    #[cfg(target_os = "linux")]
+    if let Some(appimage) = std::env::var_os("APPIMAGE") {
+        return Ok(PathBuf::from(appimage));
+    }
+
+    let exe = std::env::current_exe()
+        .map_err(|e| BackendError::io("Failed to resolve current executable", e))?;
+
+    #[cfg(target_os = "macos")]
+    {
+        if !allow_symlink {
+            let metadata = std::fs::symlink_metadata(&exe)
+                .map_err(|e| BackendError::io(format!("Failed to stat {:?}", exe), e))?;
+            if metadata.file_type().is_symlink() {
+                return Err(BackendError::Other(format!(
+                    "refusing to relaunch through symlinked path {:?} (pass allow_symlink to override)",
+                    exe
+                )));
+            }
+        }
+    }
+    #[cfg(not(target_os = "macos"))]
+    let _ = allow_symlink;
+
+    Ok(exe)
+}
+
+/// Replace this process with a fresh run of `target` (same `args`). Only
+/// returns on failure — success either replaces the process image (Unix
+/// `exec`) or spawns a detached copy and exits this one (Windows, which has
+/// no `exec` syscall).
+pub fn exec_relaunch(target: &Path, args: &[String]) -> BackendError {
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::CommandExt;
+        let err = std::process::Command::new(target).args(args).exec();
+        BackendError::io(format!("Failed to exec {:?}", target), err)
+    }
+
+    #[cfg(windows)]
+    {
+        match std::process::Command::new(target).args(args).spawn() {
+            Ok(_) => std::process::exit(0),
+            Err(e) => BackendError::io(format!("Failed to spawn {:?}", target), e),
+        }
+    }
+}
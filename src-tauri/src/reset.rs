@@ -0,0 +1,118 @@
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+
+use crate::error::BackendError;
+
+/// Names of the on-disk directories under `app_data_dir` that `reset`
+/// considers part of the backend's rebuildable state.
+const KNOWN_ROOTS: &[&str] = &["backend-venv", "config", "skills", "python", "EBWebView", "Cache"];
+
+/// One on-disk state root `reset` can report on and, for the venv(s), remove.
+#[derive(Serialize)]
+pub struct StateRoot {
+    pub name: String,
+    pub path: PathBuf,
+    pub size_bytes: u64,
+}
+
+fn is_known_root(file_name: &str) -> bool {
+    KNOWN_ROOTS.contains(&file_name) || file_name.starts_with("backend-venv-")
+}
+
+/// Enumerate the known on-disk state roots under `app_data_dir` with their
+/// sizes, so a CLI/GUI reset can show what's there before touching anything.
+pub fn enumerate_state_roots(app_data_dir: &Path) -> Vec<StateRoot> {
+    std::fs::read_dir(app_data_dir)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter(|entry| is_known_root(&entry.file_name().to_string_lossy()))
+        .map(|entry| {
+            let path = entry.path();
+            StateRoot {
+                name: entry.file_name().to_string_lossy().to_string(),
+                size_bytes: dir_size(&path),
+                path,
+            }
+        })
+        .collect()
+}
+
+fn dir_size(path: &Path) -> u64 {
+    let metadata = match std::fs::symlink_metadata(path) {
+        Ok(m) => m,
+        Err(_) => return 0,
+    };
+    if !metadata.is_dir() {
+        return metadata.len();
+    }
+    std::fs::read_dir(path)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .map(|entry| dir_size(&entry.path()))
+        .sum()
+}
+
+/// Delete the backend venv(s) (`backend-venv` / `backend-venv-*`) under
+/// `app_data_dir` and re-run first-run setup from scratch. Synced config and
+/// skills are left alone — only the interpreter/package state is corruptible
+/// enough to warrant a wipe.
+pub fn reset(
+    resource_dir: &Path,
+    app_data_dir: &Path,
+    app_handle: Option<&tauri::AppHandle>,
+    python_version: Option<&str>,
+) -> Result<(), BackendError> {
+    for entry in std::fs::read_dir(app_data_dir).into_iter().flatten().flatten() {
+        let file_name = entry.file_name().to_string_lossy().to_string();
+        if file_name == "backend-venv" || file_name.starts_with("backend-venv-") {
+            let path = entry.path();
+            std::fs::remove_dir_all(&path)
+                .map_err(|e| BackendError::io(format!("Failed to remove {:?}", path), e))?;
+        }
+    }
+
+    crate::backend::ensure_backend_setup(resource_dir, app_data_dir, app_handle, python_version)?;
+    crate::backend::sync_app_data(resource_dir, app_data_dir, app_handle)
+}
+
+/// Render a byte count the way `suzent reset` lists state roots — e.g.
+/// `128.3 MB`.
+pub fn format_size(bytes: u64) -> String {
+    const UNITS: [&str; 5] = ["B", "KB", "MB", "GB", "TB"];
+    let mut size = bytes as f64;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{} {}", bytes, UNITS[unit])
+    } else {
+        format!("{:.1} {}", size, UNITS[unit])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn is_known_root_matches_fixed_names_and_versioned_venvs() {
+        assert!(is_known_root("backend-venv"));
+        assert!(is_known_root("backend-venv-3.11"));
+        assert!(is_known_root("config"));
+        assert!(!is_known_root("chats.db"));
+        assert!(!is_known_root("backend-venvsomethingelse"));
+    }
+
+    #[test]
+    fn format_size_picks_the_right_unit() {
+        assert_eq!(format_size(0), "0 B");
+        assert_eq!(format_size(512), "512 B");
+        assert_eq!(format_size(1536), "1.5 KB");
+        assert_eq!(format_size(5 * 1024 * 1024), "5.0 MB");
+    }
+}
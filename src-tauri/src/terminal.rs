@@ -0,0 +1,187 @@
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::BackendError;
+
+/// Which terminal emulator `launch_cli_terminal` opens and how to invoke it.
+/// Persisted under `app_data_dir/term-config.json` so users whose platform
+/// detection picks the wrong terminal (or who want extra flags) can override
+/// `exec`/`args` directly from settings.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct TermConfig {
+    pub name: String,
+    pub exec: String,
+    pub args: Vec<String>,
+}
+
+fn config_path(app_data_dir: &Path) -> PathBuf {
+    app_data_dir.join("term-config.json")
+}
+
+/// Load the persisted terminal config, or probe the platform for a sensible
+/// default and persist that as a starting point.
+pub fn load_or_detect_term_config(app_data_dir: &Path) -> TermConfig {
+    let path = config_path(app_data_dir);
+    if let Ok(contents) = std::fs::read_to_string(&path) {
+        if let Ok(config) = serde_json::from_str(&contents) {
+            return config;
+        }
+    }
+
+    let config = default_term_config();
+    if let Ok(json) = serde_json::to_string_pretty(&config) {
+        let _ = std::fs::write(&path, json);
+    }
+    config
+}
+
+/// Persist a user-edited terminal config (overriding `exec`/`args` for
+/// unusual setups `default_term_config` doesn't detect correctly).
+pub fn save_term_config(app_data_dir: &Path, config: &TermConfig) -> Result<(), BackendError> {
+    let path = config_path(app_data_dir);
+    let json = serde_json::to_string_pretty(config)
+        .map_err(|e| BackendError::Other(format!("Failed to serialize terminal config: {}", e)))?;
+    std::fs::write(&path, json)
+        .map_err(|e| BackendError::io(format!("Failed to write {:?}", path), e))
+}
+
+/// Probe for a terminal emulator appropriate to the current platform.
+#[cfg(target_os = "windows")]
+fn default_term_config() -> TermConfig {
+    if let Ok(wt) = which::which("wt.exe") {
+        return TermConfig {
+            name: "Windows Terminal".to_string(),
+            exec: wt.to_string_lossy().to_string(),
+            args: vec![],
+        };
+    }
+    if let Ok(pwsh) = which::which("pwsh.exe") {
+        return TermConfig {
+            name: "PowerShell".to_string(),
+            exec: pwsh.to_string_lossy().to_string(),
+            args: vec!["-NoExit".to_string()],
+        };
+    }
+    // Always-available fallback: conhost hosting classic powershell.
+    TermConfig {
+        name: "Command Prompt".to_string(),
+        exec: "conhost.exe".to_string(),
+        args: vec!["powershell.exe".to_string(), "-NoExit".to_string()],
+    }
+}
+
+/// macOS has no CLI way to hand a new Terminal window a command to *run*
+/// (`open -a Terminal` just opens a shell and ignores forwarded argv) other
+/// than driving it through `osascript`, so `exec`/`args` here are an
+/// `osascript` invocation; `launch` below fills in the actual command as the
+/// AppleScript's `%s` placeholder rather than appending it as plain argv.
+#[cfg(target_os = "macos")]
+fn default_term_config() -> TermConfig {
+    TermConfig {
+        name: "Terminal".to_string(),
+        exec: "osascript".to_string(),
+        args: vec![
+            "-e".to_string(),
+            r#"tell application "Terminal" to do script "%s""#.to_string(),
+            "-e".to_string(),
+            r#"tell application "Terminal" to activate"#.to_string(),
+        ],
+    }
+}
+
+/// `launch` appends the executable + subcommand as trailing argv after
+/// `args`. `gnome-terminal` dropped support for passing a command straight
+/// after `-e` (it now wants a single shell-escaped string, or the command
+/// and its own args after a bare `--`); `konsole` and `xterm` still accept
+/// argv straight after `-e`.
+#[cfg(all(unix, not(target_os = "macos")))]
+fn default_term_config() -> TermConfig {
+    for (name, exec, args) in [
+        ("GNOME Terminal", "gnome-terminal", vec!["--".to_string()]),
+        ("Konsole", "konsole", vec!["-e".to_string()]),
+        ("xterm", "xterm", vec!["-e".to_string()]),
+    ] {
+        if let Ok(path) = which::which(exec) {
+            return TermConfig {
+                name: name.to_string(),
+                exec: path.to_string_lossy().to_string(),
+                args,
+            };
+        }
+    }
+    // `xterm` is the most likely to exist even unprobed, so fall back to it
+    // by name rather than erroring out.
+    TermConfig {
+        name: "xterm".to_string(),
+        exec: "xterm".to_string(),
+        args: vec!["-e".to_string()],
+    }
+}
+
+/// Spawn `config`'s terminal running the current executable with
+/// `subcommand`, inheriting `SUZENT_APP_DATA` so the CLI it opens talks to
+/// the same app data dir as the GUI.
+pub fn launch(app_data_dir: &Path, config: &TermConfig, subcommand: &[String]) -> Result<(), BackendError> {
+    let exe = std::env::current_exe()
+        .map_err(|e| BackendError::io("Failed to resolve current executable", e))?;
+
+    let mut cmd = Command::new(&config.exec);
+
+    // `osascript` has no "run this program with these args" argv convention
+    // of its own — it only understands AppleScript — so the command to run
+    // has to be spliced into the script as a shell-quoted string (the `%s`
+    // placeholder in `default_term_config`'s args) rather than appended as
+    // trailing argv like every other terminal here.
+    if is_osascript(&config.exec) {
+        // Terminal runs the script in its own shell, which does not inherit
+        // this process's environment, so SUZENT_APP_DATA goes through `env`.
+        let app_data = format!("SUZENT_APP_DATA={}", app_data_dir.to_string_lossy());
+        let command_line = applescript_escape(&format!(
+            "env '{}' {}",
+            app_data.replace('\'', r"'\''"),
+            shell_quote_command(&exe, subcommand)
+        ));
+        for arg in &config.args {
+            cmd.arg(arg.replace("%s", &command_line));
+        }
+    } else {
+        cmd.args(&config.args);
+
+        // `open` doesn't forward trailing args to the launched app on its own —
+        // it needs the explicit `--args` marker.
+        if config.exec == "open" {
+            cmd.arg("--args");
+        }
+
+        cmd.arg(&exe);
+        cmd.args(subcommand);
+    }
+
+    cmd.env("SUZENT_APP_DATA", app_data_dir);
+
+    cmd.spawn()
+        .map(|_| ())
+        .map_err(|e| BackendError::io(format!("Failed to launch terminal {:?}", config.exec), e))
+}
+
+fn is_osascript(exec: &str) -> bool {
+    Path::new(exec).file_name().is_some_and(|name| name == "osascript")
+}
+
+/// Build a single shell command line from `exe` + `subcommand`, each
+/// single-quoted, for embedding in an `osascript do script` string.
+fn shell_quote_command(exe: &Path, subcommand: &[String]) -> String {
+    std::iter::once(exe.to_string_lossy().to_string())
+        .chain(subcommand.iter().cloned())
+        .map(|part| format!("'{}'", part.replace('\'', r"'\''")))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Escape a string for embedding inside an AppleScript double-quoted string
+/// literal.
+fn applescript_escape(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"")
+}
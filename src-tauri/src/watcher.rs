@@ -0,0 +1,122 @@
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{channel, RecvTimeoutError};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use notify::{EventKind, RecursiveMode, Watcher};
+use tauri::{Emitter, Manager};
+
+use crate::AppState;
+
+const DEBOUNCE: Duration = Duration::from_millis(500);
+
+/// Watches `app_data_dir/skills` and `app_data_dir/config` and restarts the
+/// backend once changes settle, so editing a skill or config file takes
+/// effect without quitting the app. Borrows the dev-loop pattern from
+/// Tauri's own CLI: a `notify` watcher feeding debounced events into a
+/// restart loop.
+pub struct BackendWatcher {
+    cancel: Arc<AtomicBool>,
+    handle: Option<thread::JoinHandle<()>>,
+}
+
+impl BackendWatcher {
+    pub fn start(app_handle: tauri::AppHandle, app_data_dir: PathBuf) -> Self {
+        let cancel = Arc::new(AtomicBool::new(false));
+        let cancel_loop = cancel.clone();
+
+        let handle = thread::spawn(move || {
+            if let Err(e) = watch_loop(&app_handle, &app_data_dir, &cancel_loop) {
+                eprintln!("BackendWatcher: {}", e);
+            }
+        });
+
+        BackendWatcher {
+            cancel,
+            handle: Some(handle),
+        }
+    }
+
+    /// Cancel the watcher thread and wait for it to exit. Safe to call more
+    /// than once.
+    pub fn stop(&mut self) {
+        self.cancel.store(true, Ordering::SeqCst);
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+impl Drop for BackendWatcher {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+fn watch_loop(
+    app_handle: &tauri::AppHandle,
+    app_data_dir: &Path,
+    cancel: &Arc<AtomicBool>,
+) -> Result<(), String> {
+    let (tx, rx) = channel();
+    let mut watcher = notify::recommended_watcher(move |res: notify::Result<notify::Event>| {
+        if let Ok(event) = res {
+            if matches!(event.kind, EventKind::Modify(_) | EventKind::Create(_) | EventKind::Remove(_)) {
+                let _ = tx.send(());
+            }
+        }
+    }).map_err(|e| format!("Failed to create filesystem watcher: {}", e))?;
+
+    let mut watched_any = false;
+    for dir_name in &["skills", "config"] {
+        let dir = app_data_dir.join(dir_name);
+        if dir.exists() {
+            watcher.watch(&dir, RecursiveMode::Recursive)
+                .map_err(|e| format!("Failed to watch {:?}: {}", dir, e))?;
+            watched_any = true;
+        }
+    }
+
+    if !watched_any {
+        return Err(format!("Neither skills nor config directory found under {:?}", app_data_dir));
+    }
+
+    let mut last_event: Option<Instant> = None;
+
+    loop {
+        if cancel.load(Ordering::SeqCst) {
+            return Ok(());
+        }
+
+        match rx.recv_timeout(Duration::from_millis(100)) {
+            Ok(()) => last_event = Some(Instant::now()),
+            Err(RecvTimeoutError::Timeout) => {}
+            Err(RecvTimeoutError::Disconnected) => return Ok(()),
+        }
+
+        if let Some(t) = last_event {
+            if t.elapsed() >= DEBOUNCE {
+                last_event = None;
+                println!("BackendWatcher: detected change in skills/config, restarting backend...");
+                restart_backend(app_handle, app_data_dir);
+            }
+        }
+    }
+}
+
+fn restart_backend(app_handle: &tauri::AppHandle, app_data_dir: &Path) {
+    let Some(state) = app_handle.try_state::<AppState>() else { return };
+    let Ok(mut guard) = state.backend.lock() else { return };
+
+    if let Some(backend) = guard.as_mut() {
+        match backend.restart(app_handle, app_data_dir) {
+            Ok(port) => {
+                println!("BackendWatcher: backend restarted on port {}", port);
+                let _ = app_handle.emit_to(tauri::EventTarget::any(), "backend-restarted", port);
+            }
+            Err(e) => eprintln!("BackendWatcher: failed to restart backend: {}", e),
+        }
+    }
+}